@@ -1,49 +1,189 @@
+//! Async counterpart of [`crate::sha2_hasher`], built on `tokio`. Gated behind the
+//! `tokio` feature so that callers who only need the sync API don't pull in a runtime.
+//! Shares `Algorithm`, `CheckResult` and the manifest helpers with the sync module
+//! rather than redefining them, so the two stay in sync; only the I/O and concurrency
+//! primitives (`AsyncRead`, `JoinSet`/`Semaphore`) differ.
+
 use std::{
+    fmt::Write as _,
     future::Future,
     io::{
         Error,
         ErrorKind::{InvalidInput, NotFound},
     },
-    path::Path,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread::available_parallelism,
 };
 
 use const_hex::ToHexExt;
-use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
-use tokio::fs::read;
+use sha2::Digest;
+#[cfg(feature = "sha224")]
+use sha2::Sha224;
+#[cfg(feature = "sha256")]
+use sha2::Sha256;
+#[cfg(feature = "sha384")]
+use sha2::Sha384;
+#[cfg(feature = "sha512")]
+use sha2::Sha512;
+use tokio::{
+    fs::{self, File},
+    io::AsyncReadExt,
+    sync::Semaphore,
+    task::JoinSet,
+};
+
+use crate::{
+    sha2_hasher::{constant_time_eq, parse_manifest_line, relative_to},
+    Algorithm, CheckResult,
+};
 
 pub trait Sha2Hasher {
     /// Hashes with the SHA-224 algorithm.
+    #[cfg(feature = "sha224")]
     fn sha224(&self) -> impl Future<Output = Result<String, Error>> + Send;
 
     /// Hashes with the SHA-256 algorithm.
+    #[cfg(feature = "sha256")]
     fn sha256(&self) -> impl Future<Output = Result<String, Error>> + Send;
 
     /// Hashes with the SHA-384 algorithm.
+    #[cfg(feature = "sha384")]
     fn sha384(&self) -> impl Future<Output = Result<String, Error>> + Send;
 
     /// Hashes with the SHA-512 algorithm.
+    #[cfg(feature = "sha512")]
     fn sha512(&self) -> impl Future<Output = Result<String, Error>> + Send;
+
+    /// Hashes with the algorithm selected at runtime.
+    fn hash(&self, algo: Algorithm) -> impl Future<Output = Result<String, Error>> + Send;
+
+    /// Hashes the file and compares the digest against `expected` (hex, either case,
+    /// surrounding whitespace tolerated) in constant time.
+    fn verify(
+        &self,
+        algo: Algorithm,
+        expected: &str,
+    ) -> impl Future<Output = Result<bool, Error>> + Send;
 }
 
+/// Implement the `Sha2Hasher` trait for any type that can be converted to a `Path`.
 impl<P> Sha2Hasher for P
 where
     P: AsRef<Path> + Sync,
 {
+    #[cfg(feature = "sha224")]
     async fn sha224(&self) -> Result<String, Error> {
         hash_file::<Sha224, _>(self).await
     }
 
+    #[cfg(feature = "sha256")]
     async fn sha256(&self) -> Result<String, Error> {
         hash_file::<Sha256, _>(self).await
     }
 
+    #[cfg(feature = "sha384")]
     async fn sha384(&self) -> Result<String, Error> {
         hash_file::<Sha384, _>(self).await
     }
 
+    #[cfg(feature = "sha512")]
     async fn sha512(&self) -> Result<String, Error> {
         hash_file::<Sha512, _>(self).await
     }
+
+    async fn hash(&self, algo: Algorithm) -> Result<String, Error> {
+        match algo {
+            #[cfg(feature = "sha224")]
+            Algorithm::Sha224 => hash_file::<Sha224, _>(self).await,
+            #[cfg(feature = "sha256")]
+            Algorithm::Sha256 => hash_file::<Sha256, _>(self).await,
+            #[cfg(feature = "sha384")]
+            Algorithm::Sha384 => hash_file::<Sha384, _>(self).await,
+            #[cfg(feature = "sha512")]
+            Algorithm::Sha512 => hash_file::<Sha512, _>(self).await,
+        }
+    }
+
+    async fn verify(&self, algo: Algorithm, expected: &str) -> Result<bool, Error> {
+        let expected = expected.trim().to_ascii_lowercase();
+        if expected.len() != algo.output_len() {
+            return Err(Error::new(
+                InvalidInput,
+                "Expected digest has the wrong length for the algorithm",
+            ));
+        }
+        let actual = self.hash(algo).await?;
+        Ok(constant_time_eq(actual.as_bytes(), expected.as_bytes()))
+    }
+}
+
+/// Hashes in-memory data (byte slices, `String`s, `Vec<u8>`, ...) directly, without
+/// writing it to a file first. Kept as a separate trait from [`Sha2Hasher`] so that
+/// types implementing both `AsRef<Path>` and `AsRef<[u8]>` (e.g. `&str`) aren't forced
+/// into one blanket impl or the other. Methods are suffixed with `_bytes` so that both
+/// traits can be in scope at once without an ambiguous-method-resolution error on such
+/// types.
+pub trait Sha2HashBytes {
+    /// Hashes with the SHA-224 algorithm.
+    #[cfg(feature = "sha224")]
+    fn sha224_bytes(&self) -> impl Future<Output = String> + Send;
+
+    /// Hashes with the SHA-256 algorithm.
+    #[cfg(feature = "sha256")]
+    fn sha256_bytes(&self) -> impl Future<Output = String> + Send;
+
+    /// Hashes with the SHA-384 algorithm.
+    #[cfg(feature = "sha384")]
+    fn sha384_bytes(&self) -> impl Future<Output = String> + Send;
+
+    /// Hashes with the SHA-512 algorithm.
+    #[cfg(feature = "sha512")]
+    fn sha512_bytes(&self) -> impl Future<Output = String> + Send;
+
+    /// Hashes with the algorithm selected at runtime.
+    fn hash_bytes(&self, algo: Algorithm) -> impl Future<Output = String> + Send;
+}
+
+impl<B> Sha2HashBytes for B
+where
+    B: AsRef<[u8]> + Sync,
+{
+    #[cfg(feature = "sha224")]
+    async fn sha224_bytes(&self) -> String {
+        digest_bytes::<Sha224>(self.as_ref()).await
+    }
+
+    #[cfg(feature = "sha256")]
+    async fn sha256_bytes(&self) -> String {
+        digest_bytes::<Sha256>(self.as_ref()).await
+    }
+
+    #[cfg(feature = "sha384")]
+    async fn sha384_bytes(&self) -> String {
+        digest_bytes::<Sha384>(self.as_ref()).await
+    }
+
+    #[cfg(feature = "sha512")]
+    async fn sha512_bytes(&self) -> String {
+        digest_bytes::<Sha512>(self.as_ref()).await
+    }
+
+    async fn hash_bytes(&self, algo: Algorithm) -> String {
+        match algo {
+            #[cfg(feature = "sha224")]
+            Algorithm::Sha224 => digest_bytes::<Sha224>(self.as_ref()).await,
+            #[cfg(feature = "sha256")]
+            Algorithm::Sha256 => digest_bytes::<Sha256>(self.as_ref()).await,
+            #[cfg(feature = "sha384")]
+            Algorithm::Sha384 => digest_bytes::<Sha384>(self.as_ref()).await,
+            #[cfg(feature = "sha512")]
+            Algorithm::Sha512 => digest_bytes::<Sha512>(self.as_ref()).await,
+        }
+    }
 }
 
 #[inline]
@@ -60,46 +200,237 @@ where
         ));
     }
 
+    hash_reader::<D, _>(File::open(path).await?).await
+}
+
+/// Hashes the in-memory byte slice. Infallible, since reading from a slice cannot fail.
+#[inline]
+async fn digest_bytes<D: Digest>(bytes: &[u8]) -> String {
+    hash_reader::<D, _>(std::io::Cursor::new(bytes))
+        .await
+        .expect("hashing an in-memory byte slice cannot fail")
+}
+
+#[inline]
+async fn hash_reader<D, R>(mut reader: R) -> Result<String, Error>
+where
+    D: Digest,
+    R: AsyncReadExt + Unpin,
+{
     let mut hasher = D::new();
-    hasher.update(read(path).await?);
+    let mut buf = [0u8; 65536];
+    loop {
+        let n = reader.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
     Ok(hasher.finalize().encode_hex())
 }
 
-#[cfg(test)]
-mod tests {
-    use std::path::Path;
+/// Options controlling [`hash_dir_sha224`]/[`hash_dir_sha256`]/[`hash_dir_sha384`]/
+/// [`hash_dir_sha512`]'s concurrency and progress reporting.
+pub struct HashDirOptions {
+    /// Number of files to hash concurrently. Defaults to the available parallelism.
+    pub jobs: usize,
+    /// Called after each file completes with `(done, total)`.
+    pub progress: Option<Arc<dyn Fn(usize, usize) + Send + Sync>>,
+}
+
+impl Default for HashDirOptions {
+    fn default() -> Self {
+        Self {
+            jobs: available_parallelism().map(|n| n.get()).unwrap_or(1),
+            progress: None,
+        }
+    }
+}
+
+/// Recursively hashes every regular file under `root`, running up to `opts.jobs` hashes
+/// concurrently, and returns one result per file, followed by an error entry for each
+/// directory or directory entry that could not be read.
+#[cfg(feature = "sha224")]
+pub async fn hash_dir_sha224<P: AsRef<Path>>(
+    root: P,
+    opts: HashDirOptions,
+) -> Vec<(PathBuf, Result<String, Error>)> {
+    hash_dir::<Sha224, _>(root, opts).await
+}
+
+/// Recursively hashes every regular file under `root`, running up to `opts.jobs` hashes
+/// concurrently, and returns one result per file, followed by an error entry for each
+/// directory or directory entry that could not be read.
+#[cfg(feature = "sha256")]
+pub async fn hash_dir_sha256<P: AsRef<Path>>(
+    root: P,
+    opts: HashDirOptions,
+) -> Vec<(PathBuf, Result<String, Error>)> {
+    hash_dir::<Sha256, _>(root, opts).await
+}
+
+/// Recursively hashes every regular file under `root`, running up to `opts.jobs` hashes
+/// concurrently, and returns one result per file, followed by an error entry for each
+/// directory or directory entry that could not be read.
+#[cfg(feature = "sha384")]
+pub async fn hash_dir_sha384<P: AsRef<Path>>(
+    root: P,
+    opts: HashDirOptions,
+) -> Vec<(PathBuf, Result<String, Error>)> {
+    hash_dir::<Sha384, _>(root, opts).await
+}
+
+/// Recursively hashes every regular file under `root`, running up to `opts.jobs` hashes
+/// concurrently, and returns one result per file, followed by an error entry for each
+/// directory or directory entry that could not be read.
+#[cfg(feature = "sha512")]
+pub async fn hash_dir_sha512<P: AsRef<Path>>(
+    root: P,
+    opts: HashDirOptions,
+) -> Vec<(PathBuf, Result<String, Error>)> {
+    hash_dir::<Sha512, _>(root, opts).await
+}
 
-    use crate::Sha2Hasher;
+async fn hash_dir<D, P>(root: P, opts: HashDirOptions) -> Vec<(PathBuf, Result<String, Error>)>
+where
+    D: Digest + Send + 'static,
+    P: AsRef<Path>,
+{
+    let (files, errors) = collect_files(root.as_ref()).await;
+    let total = files.len();
+    let semaphore = Arc::new(Semaphore::new(opts.jobs.max(1)));
+    let done = Arc::new(AtomicUsize::new(0));
+    let progress = opts.progress;
 
-    const TEST_FILE: &str = "tests/data/test.txt";
+    let mut set = JoinSet::new();
+    for path in files {
+        let semaphore = Arc::clone(&semaphore);
+        let done = Arc::clone(&done);
+        let progress = progress.clone();
+        set.spawn(async move {
+            let _permit = semaphore.acquire_owned().await.expect("semaphore closed");
+            let result = hash_file::<D, _>(&path).await;
+            let completed = done.fetch_add(1, Ordering::Relaxed) + 1;
+            if let Some(progress) = &progress {
+                progress(completed, total);
+            }
+            (path, result)
+        });
+    }
 
-    #[tokio::test]
-    async fn sha224() {
-        let hash = Path::new(TEST_FILE).sha224().await.unwrap();
-        assert_eq!(hash, "c547cf5d6bf6b795abbe4c5cc7cac00f1d5ec17bcd74281ea89e6108");
+    let mut results = Vec::with_capacity(total);
+    while let Some(joined) = set.join_next().await {
+        if let Ok(item) = joined {
+            results.push(item);
+        }
     }
+    results.extend(errors);
+    results
+}
 
-    #[tokio::test]
-    async fn sha256() {
-        let hash = Path::new(TEST_FILE).sha256().await.unwrap();
-        assert_eq!(hash, "c98c24b677eff44860afea6f493bbaec5bb1c4cbb209c6fc2bbb47f66ff2ad31");
+/// Walks `root` depth-first, returning every regular file found alongside an error entry
+/// for each directory or directory entry that could not be read (permission denied,
+/// removed mid-walk, ...). Does not follow symlinks, so a symlink cycle under `root`
+/// cannot cause an infinite walk.
+async fn collect_files(root: &Path) -> (Vec<PathBuf>, Vec<(PathBuf, Result<String, Error>)>) {
+    let mut files = Vec::new();
+    let mut errors = Vec::new();
+    let mut stack = vec![root.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        let mut entries = match fs::read_dir(&dir).await {
+            Ok(entries) => entries,
+            Err(err) => {
+                errors.push((dir, Err(err)));
+                continue;
+            }
+        };
+        loop {
+            let entry = match entries.next_entry().await {
+                Ok(Some(entry)) => entry,
+                Ok(None) => break,
+                Err(err) => {
+                    errors.push((dir.clone(), Err(err)));
+                    break;
+                }
+            };
+            let path = entry.path();
+            let file_type = match entry.file_type().await {
+                Ok(file_type) => file_type,
+                Err(err) => {
+                    errors.push((path, Err(err)));
+                    continue;
+                }
+            };
+            if file_type.is_dir() {
+                stack.push(path);
+            } else if file_type.is_file() {
+                files.push(path);
+            }
+        }
     }
+    (files, errors)
+}
+
+/// Parses a `sha256sum`-style checksum manifest (`<hexdigest>␣␣<relative-path>`, with
+/// an optional `*` binary marker before the path) and checks each listed file, resolving
+/// relative paths against the manifest's directory.
+pub async fn verify_manifest<P>(
+    path: P,
+    algo: Algorithm,
+) -> Result<Vec<(PathBuf, CheckResult)>, Error>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let contents = fs::read_to_string(path).await?;
 
-    #[tokio::test]
-    async fn sha384() {
-        let hash = Path::new(TEST_FILE).sha384().await.unwrap();
-        assert_eq!(
-            hash,
-            "d195483c9b554356ba50a855a605aaee134612dcfdd05988fc605181d93603f215a0d07812a0b333fc2ccc75025736f5"
-        );
+    let mut results = Vec::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let Some((expected, rel_path)) = parse_manifest_line(line) else {
+            continue;
+        };
+        let expected = expected.to_ascii_lowercase();
+        let file = dir.join(rel_path);
+
+        let result = if !file.is_file() {
+            CheckResult::Missing
+        } else {
+            match file.hash(algo).await {
+                Ok(actual) if constant_time_eq(actual.as_bytes(), expected.as_bytes()) => {
+                    CheckResult::Ok
+                }
+                Ok(actual) => CheckResult::Mismatch { expected, actual },
+                Err(err) => CheckResult::Error(err.to_string()),
+            }
+        };
+        results.push((PathBuf::from(rel_path), result));
     }
+    Ok(results)
+}
+
+/// Writes a `sha256sum`-style checksum manifest listing the digest of each file in
+/// `files`, relative to `manifest`'s directory.
+pub async fn write_manifest<P, F, I>(manifest: P, algo: Algorithm, files: I) -> Result<(), Error>
+where
+    P: AsRef<Path>,
+    F: AsRef<Path>,
+    I: IntoIterator<Item = F>,
+{
+    let manifest = manifest.as_ref();
+    let dir = manifest.parent().unwrap_or_else(|| Path::new("."));
 
-    #[tokio::test]
-    async fn sha512() {
-        let hash = Path::new(TEST_FILE).sha512().await.unwrap();
-        assert_eq!(
-            hash,
-            "921618bc6d9f8059437c5e0397b13f973ab7c7a7b81f0ca31b70bf448fd800a460b67efda0020088bc97bf7d9da97a9e2ce7b20d46e066462ec44cf60284f9a7"
-        );
+    let mut contents = String::new();
+    for file in files {
+        let file = file.as_ref();
+        let digest = file.hash(algo).await?;
+        let rel_path = relative_to(file, dir)?;
+        writeln!(contents, "{digest}  {}", rel_path.display())
+            .expect("writing to a String never fails");
     }
+    fs::write(manifest, contents).await
 }
@@ -1,6 +1,19 @@
 #![doc = include_str!("../README.md")]
 mod sha2_hasher;
-pub use sha2_hasher::Sha2Hasher;
+#[cfg(feature = "tokio")]
+pub mod r#async;
+#[cfg(feature = "sha224")]
+pub use sha2_hasher::hash_dir_sha224;
+#[cfg(feature = "sha256")]
+pub use sha2_hasher::hash_dir_sha256;
+#[cfg(feature = "sha384")]
+pub use sha2_hasher::hash_dir_sha384;
+#[cfg(feature = "sha512")]
+pub use sha2_hasher::hash_dir_sha512;
+pub use sha2_hasher::{
+    verify_manifest, write_manifest, Algorithm, CheckResult, HashDirOptions, Sha2HashBytes,
+    Sha2Hasher,
+};
 
 #[cfg(test)]
 mod tests {
@@ -26,4 +39,249 @@ mod tests {
     test!(sha384, "16c6a6c5fb77fb778b0739b93005a54bf4d5d011ecfc151d1d28680df65829fb25e4f639d12ea5bd0d95fb15a02a9d46");
     #[cfg(feature = "sha512")]
     test!(sha512, "cce95db66253cee0b4543434b0a93382fdd876996f0783709144d7317cc1686b97f907a4f18da2bdf95461b140129eb93242a842b3eee0878973ac139482db54");
+
+    #[cfg(feature = "sha256")]
+    mod hash_dir {
+        use std::collections::HashMap;
+
+        use crate::{hash_dir_sha256, HashDirOptions};
+
+        #[test]
+        fn hashes_every_file_in_the_tree() {
+            let results = hash_dir_sha256("tests/fixtures/dir", HashDirOptions::default());
+            let digests: HashMap<String, String> = results
+                .into_iter()
+                .map(|(path, digest)| (path.to_string_lossy().into_owned(), digest.unwrap()))
+                .collect();
+
+            assert_eq!(digests.len(), 2);
+            assert_eq!(
+                digests["tests/fixtures/dir/a.txt"],
+                "0b2f1cd65b581e676a7af42de043d677f30ae8ffeae349662d78e012c5266395"
+            );
+            assert_eq!(
+                digests["tests/fixtures/dir/sub/b.txt"],
+                "ae3ab3adf51091abfded2d808d9d129ec31924acec8c0b24727f5b4c1877199d"
+            );
+        }
+    }
+
+    #[cfg(feature = "sha256")]
+    mod algorithm {
+        use std::str::FromStr;
+
+        use crate::Algorithm;
+
+        #[test]
+        fn from_str_accepts_common_spellings() {
+            for spelling in ["sha256", "SHA256", "SHA-256", "sha_256", "Sha 256"] {
+                assert_eq!(Algorithm::from_str(spelling).unwrap(), Algorithm::Sha256);
+            }
+        }
+
+        #[test]
+        fn from_str_rejects_unknown_algorithm() {
+            assert!(Algorithm::from_str("md5").is_err());
+        }
+
+        #[test]
+        fn display_matches_canonical_spelling() {
+            assert_eq!(Algorithm::Sha256.to_string(), "SHA-256");
+        }
+    }
+
+    #[cfg(feature = "sha256")]
+    mod verify {
+        use std::path::Path;
+
+        use crate::{Algorithm, Sha2Hasher};
+
+        #[test]
+        fn accepts_matching_digest_regardless_of_case() {
+            let file = Path::new(".gitignore");
+            assert!(file
+                .verify(
+                    Algorithm::Sha256,
+                    "44C92E3A70AD3307B7056871C2BDB096D8BFA9373F5BF06A79BB6324A20FF2FB"
+                )
+                .unwrap());
+        }
+
+        #[test]
+        fn rejects_mismatched_digest() {
+            let file = Path::new(".gitignore");
+            assert!(!file.verify(Algorithm::Sha256, &"0".repeat(64)).unwrap());
+        }
+
+        #[test]
+        fn rejects_wrong_length_digest() {
+            let file = Path::new(".gitignore");
+            assert!(file.verify(Algorithm::Sha256, "deadbeef").is_err());
+        }
+    }
+
+    #[cfg(feature = "sha256")]
+    mod manifest {
+        use std::{
+            fs,
+            path::{Path, PathBuf},
+            process,
+        };
+
+        use crate::{verify_manifest, write_manifest, Algorithm, CheckResult};
+
+        fn temp_manifest_path(name: &str) -> PathBuf {
+            // Lives inside the fixture directory itself (a directory dedicated to
+            // these tests, separate from the one hash_dir's tests scan), so its
+            // entries' paths are relative to a directory the fixture files are
+            // actually under.
+            Path::new("tests/fixtures/manifest").join(format!("{}-{}.sha256", process::id(), name))
+        }
+
+        #[test]
+        fn write_then_verify_round_trip() {
+            let manifest = temp_manifest_path("round-trip");
+            let files = ["tests/fixtures/manifest/a.txt"];
+            write_manifest(&manifest, Algorithm::Sha256, files).unwrap();
+
+            let results = verify_manifest(&manifest, Algorithm::Sha256).unwrap();
+            fs::remove_file(&manifest).unwrap();
+
+            assert_eq!(results.len(), 1);
+            assert!(results.iter().all(|(_, result)| *result == CheckResult::Ok));
+        }
+
+        #[test]
+        fn reports_mismatch_and_missing_entries() {
+            let manifest = temp_manifest_path("mismatch-missing");
+            fs::write(
+                &manifest,
+                format!(
+                    "{}  a.txt\n{}  does-not-exist.txt\n",
+                    "0".repeat(64),
+                    "1".repeat(64)
+                ),
+            )
+            .unwrap();
+
+            let results = verify_manifest(&manifest, Algorithm::Sha256).unwrap();
+            fs::remove_file(&manifest).unwrap();
+
+            assert!(matches!(
+                results
+                    .iter()
+                    .find(|(path, _)| path == Path::new("a.txt"))
+                    .unwrap()
+                    .1,
+                CheckResult::Mismatch { .. }
+            ));
+            assert_eq!(
+                results
+                    .iter()
+                    .find(|(path, _)| path == Path::new("does-not-exist.txt"))
+                    .unwrap()
+                    .1,
+                CheckResult::Missing
+            );
+        }
+    }
+
+    #[cfg(feature = "sha256")]
+    mod hash_bytes {
+        use crate::{Algorithm, Sha2HashBytes};
+
+        const EXPECTED: &str = "50dcaf3807b023f9d520293cccdd16d61139155d67a888872c38b7c094a881ca";
+
+        #[test]
+        fn hashes_a_str() {
+            assert_eq!("sha2_hasher crate".sha256_bytes(), EXPECTED);
+        }
+
+        #[test]
+        fn hashes_via_runtime_algorithm_selection() {
+            let bytes: Vec<u8> = "sha2_hasher crate".bytes().collect();
+            assert_eq!(bytes.hash_bytes(Algorithm::Sha256), EXPECTED);
+        }
+    }
+
+    #[cfg(all(feature = "sha256", feature = "tokio"))]
+    mod r#async {
+        use std::{collections::HashMap, path::Path};
+
+        use crate::{
+            r#async::{
+                hash_dir_sha256, verify_manifest, write_manifest, HashDirOptions, Sha2HashBytes,
+                Sha2Hasher,
+            },
+            Algorithm, CheckResult,
+        };
+
+        #[tokio::test]
+        async fn hashes_a_file() {
+            let hash = Path::new(".gitignore").sha256().await.unwrap();
+            assert_eq!(
+                hash,
+                "44c92e3a70ad3307b7056871c2bdb096d8bfa9373f5bf06a79bb6324a20ff2fb"
+            );
+        }
+
+        #[tokio::test]
+        async fn verifies_a_file() {
+            let file = Path::new(".gitignore");
+            assert!(file
+                .verify(
+                    Algorithm::Sha256,
+                    "44C92E3A70AD3307B7056871C2BDB096D8BFA9373F5BF06A79BB6324A20FF2FB"
+                )
+                .await
+                .unwrap());
+            assert!(!file
+                .verify(Algorithm::Sha256, &"0".repeat(64))
+                .await
+                .unwrap());
+        }
+
+        #[tokio::test]
+        async fn hashes_a_str() {
+            assert_eq!(
+                "sha2_hasher crate".sha256_bytes().await,
+                "50dcaf3807b023f9d520293cccdd16d61139155d67a888872c38b7c094a881ca"
+            );
+        }
+
+        #[tokio::test]
+        async fn hashes_every_file_in_the_tree() {
+            let results = hash_dir_sha256("tests/fixtures/dir", HashDirOptions::default()).await;
+            let digests: HashMap<String, String> = results
+                .into_iter()
+                .map(|(path, digest)| (path.to_string_lossy().into_owned(), digest.unwrap()))
+                .collect();
+
+            assert_eq!(digests.len(), 2);
+            assert_eq!(
+                digests["tests/fixtures/dir/a.txt"],
+                "0b2f1cd65b581e676a7af42de043d677f30ae8ffeae349662d78e012c5266395"
+            );
+            assert_eq!(
+                digests["tests/fixtures/dir/sub/b.txt"],
+                "ae3ab3adf51091abfded2d808d9d129ec31924acec8c0b24727f5b4c1877199d"
+            );
+        }
+
+        #[tokio::test]
+        async fn writes_then_verifies_a_manifest() {
+            let manifest = Path::new("tests/fixtures/manifest")
+                .join(format!("{}-async-round-trip.sha256", std::process::id()));
+            let files = ["tests/fixtures/manifest/a.txt"];
+            write_manifest(&manifest, Algorithm::Sha256, files)
+                .await
+                .unwrap();
+
+            let results = verify_manifest(&manifest, Algorithm::Sha256).await.unwrap();
+            tokio::fs::remove_file(&manifest).await.unwrap();
+
+            assert_eq!(results.len(), 1);
+            assert!(results.iter().all(|(_, result)| *result == CheckResult::Ok));
+        }
+    }
 }
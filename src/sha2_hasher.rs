@@ -1,13 +1,19 @@
 use std::{
-    fs::read,
+    fmt::{self, Write as _},
+    fs::{self, File},
     io::{
         Error,
         ErrorKind::{InvalidInput, NotFound},
+        Read,
     },
-    path::Path,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread::available_parallelism,
 };
 
 use const_hex::ToHexExt;
+use rayon::{ThreadPoolBuilder, iter::{IntoParallelIterator, ParallelIterator}};
 use sha2::Digest;
 #[cfg(feature = "sha224")]
 use sha2::Sha224;
@@ -34,6 +40,13 @@ pub trait Sha2Hasher {
     /// Hashes with the SHA-512 algorithm.
     #[cfg(feature = "sha512")]
     fn sha512(&self) -> Result<String, Error>;
+
+    /// Hashes with the algorithm selected at runtime.
+    fn hash(&self, algo: Algorithm) -> Result<String, Error>;
+
+    /// Hashes the file and compares the digest against `expected` (hex, either case,
+    /// surrounding whitespace tolerated) in constant time.
+    fn verify(&self, algo: Algorithm, expected: &str) -> Result<bool, Error>;
 }
 
 /// Implement the `Sha2Hasher` trait for any type that can be converted to a `Path`.
@@ -60,6 +73,169 @@ where
     fn sha512(&self) -> Result<String, Error> {
         hash_file::<Sha512, _>(self)
     }
+
+    fn hash(&self, algo: Algorithm) -> Result<String, Error> {
+        match algo {
+            #[cfg(feature = "sha224")]
+            Algorithm::Sha224 => hash_file::<Sha224, _>(self),
+            #[cfg(feature = "sha256")]
+            Algorithm::Sha256 => hash_file::<Sha256, _>(self),
+            #[cfg(feature = "sha384")]
+            Algorithm::Sha384 => hash_file::<Sha384, _>(self),
+            #[cfg(feature = "sha512")]
+            Algorithm::Sha512 => hash_file::<Sha512, _>(self),
+        }
+    }
+
+    fn verify(&self, algo: Algorithm, expected: &str) -> Result<bool, Error> {
+        let expected = expected.trim().to_ascii_lowercase();
+        if expected.len() != algo.output_len() {
+            return Err(Error::new(
+                InvalidInput,
+                "Expected digest has the wrong length for the algorithm",
+            ));
+        }
+        let actual = self.hash(algo)?;
+        Ok(constant_time_eq(actual.as_bytes(), expected.as_bytes()))
+    }
+}
+
+/// Compares two byte slices without branching on their contents, to avoid leaking
+/// digest comparisons through timing.
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// Hashes in-memory data (byte slices, `String`s, `Vec<u8>`, ...) directly, without
+/// writing it to a file first. Kept as a separate trait from [`Sha2Hasher`] so that
+/// types implementing both `AsRef<Path>` and `AsRef<[u8]>` (e.g. `&str`) aren't forced
+/// into one blanket impl or the other. Methods are suffixed with `_bytes` so that both
+/// traits can be in scope at once without an ambiguous-method-resolution error on such
+/// types.
+pub trait Sha2HashBytes {
+    /// Hashes with the SHA-224 algorithm.
+    #[cfg(feature = "sha224")]
+    fn sha224_bytes(&self) -> String;
+
+    /// Hashes with the SHA-256 algorithm.
+    #[cfg(feature = "sha256")]
+    fn sha256_bytes(&self) -> String;
+
+    /// Hashes with the SHA-384 algorithm.
+    #[cfg(feature = "sha384")]
+    fn sha384_bytes(&self) -> String;
+
+    /// Hashes with the SHA-512 algorithm.
+    #[cfg(feature = "sha512")]
+    fn sha512_bytes(&self) -> String;
+
+    /// Hashes with the algorithm selected at runtime.
+    fn hash_bytes(&self, algo: Algorithm) -> String;
+}
+
+impl<B> Sha2HashBytes for B
+where
+    B: AsRef<[u8]>,
+{
+    #[cfg(feature = "sha224")]
+    fn sha224_bytes(&self) -> String {
+        digest_bytes::<Sha224>(self.as_ref())
+    }
+
+    #[cfg(feature = "sha256")]
+    fn sha256_bytes(&self) -> String {
+        digest_bytes::<Sha256>(self.as_ref())
+    }
+
+    #[cfg(feature = "sha384")]
+    fn sha384_bytes(&self) -> String {
+        digest_bytes::<Sha384>(self.as_ref())
+    }
+
+    #[cfg(feature = "sha512")]
+    fn sha512_bytes(&self) -> String {
+        digest_bytes::<Sha512>(self.as_ref())
+    }
+
+    fn hash_bytes(&self, algo: Algorithm) -> String {
+        match algo {
+            #[cfg(feature = "sha224")]
+            Algorithm::Sha224 => digest_bytes::<Sha224>(self.as_ref()),
+            #[cfg(feature = "sha256")]
+            Algorithm::Sha256 => digest_bytes::<Sha256>(self.as_ref()),
+            #[cfg(feature = "sha384")]
+            Algorithm::Sha384 => digest_bytes::<Sha384>(self.as_ref()),
+            #[cfg(feature = "sha512")]
+            Algorithm::Sha512 => digest_bytes::<Sha512>(self.as_ref()),
+        }
+    }
+}
+
+/// A SHA-2 variant, selectable at runtime (e.g. from a CLI flag or config string).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    #[cfg(feature = "sha224")]
+    Sha224,
+    #[cfg(feature = "sha256")]
+    Sha256,
+    #[cfg(feature = "sha384")]
+    Sha384,
+    #[cfg(feature = "sha512")]
+    Sha512,
+}
+
+impl Algorithm {
+    /// The length, in hex characters, of a digest produced by this algorithm.
+    pub(crate) fn output_len(self) -> usize {
+        match self {
+            #[cfg(feature = "sha224")]
+            Algorithm::Sha224 => 56,
+            #[cfg(feature = "sha256")]
+            Algorithm::Sha256 => 64,
+            #[cfg(feature = "sha384")]
+            Algorithm::Sha384 => 96,
+            #[cfg(feature = "sha512")]
+            Algorithm::Sha512 => 128,
+        }
+    }
+}
+
+impl FromStr for Algorithm {
+    type Err = Error;
+
+    /// Accepts common spellings such as "SHA-256", "sha256" and "SHA256".
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let normalized = s.to_ascii_lowercase().replace(['-', '_', ' '], "");
+        match normalized.as_str() {
+            #[cfg(feature = "sha224")]
+            "sha224" => Ok(Algorithm::Sha224),
+            #[cfg(feature = "sha256")]
+            "sha256" => Ok(Algorithm::Sha256),
+            #[cfg(feature = "sha384")]
+            "sha384" => Ok(Algorithm::Sha384),
+            #[cfg(feature = "sha512")]
+            "sha512" => Ok(Algorithm::Sha512),
+            _ => Err(Error::new(InvalidInput, format!("Unknown algorithm: {s}"))),
+        }
+    }
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            #[cfg(feature = "sha224")]
+            Algorithm::Sha224 => "SHA-224",
+            #[cfg(feature = "sha256")]
+            Algorithm::Sha256 => "SHA-256",
+            #[cfg(feature = "sha384")]
+            Algorithm::Sha384 => "SHA-384",
+            #[cfg(feature = "sha512")]
+            Algorithm::Sha512 => "SHA-512",
+        })
+    }
 }
 
 #[inline]
@@ -76,7 +252,263 @@ where
         ));
     }
 
+    hash_reader::<D, _>(File::open(path)?)
+}
+
+/// Hashes the in-memory byte slice. Infallible, since reading from a slice cannot fail.
+#[inline]
+fn digest_bytes<D: Digest>(bytes: &[u8]) -> String {
+    hash_reader::<D, _>(bytes).expect("hashing an in-memory byte slice cannot fail")
+}
+
+#[inline]
+fn hash_reader<D, R>(mut reader: R) -> Result<String, Error>
+where
+    D: Digest,
+    R: Read,
+{
     let mut hasher = D::new();
-    hasher.update(read(path)?);
+    let mut buf = [0u8; 65536];
+    loop {
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
     Ok(hasher.finalize().encode_hex())
 }
+
+/// Options controlling [`hash_dir_sha224`]/[`hash_dir_sha256`]/[`hash_dir_sha384`]/
+/// [`hash_dir_sha512`]'s concurrency and progress reporting.
+pub struct HashDirOptions {
+    /// Number of files to hash concurrently. Defaults to the available parallelism.
+    pub jobs: usize,
+    /// Called after each file completes with `(done, total)`.
+    pub progress: Option<Box<dyn Fn(usize, usize) + Send + Sync>>,
+}
+
+impl Default for HashDirOptions {
+    fn default() -> Self {
+        Self {
+            jobs: available_parallelism().map(|n| n.get()).unwrap_or(1),
+            progress: None,
+        }
+    }
+}
+
+/// Recursively hashes every regular file under `root`, in parallel, returning one
+/// result per file, followed by an error entry for each directory or directory entry
+/// that could not be read.
+#[cfg(feature = "sha224")]
+pub fn hash_dir_sha224<P: AsRef<Path>>(
+    root: P,
+    opts: HashDirOptions,
+) -> Vec<(PathBuf, Result<String, Error>)> {
+    hash_dir::<Sha224, _>(root, opts)
+}
+
+/// Recursively hashes every regular file under `root`, in parallel, returning one
+/// result per file, followed by an error entry for each directory or directory entry
+/// that could not be read.
+#[cfg(feature = "sha256")]
+pub fn hash_dir_sha256<P: AsRef<Path>>(
+    root: P,
+    opts: HashDirOptions,
+) -> Vec<(PathBuf, Result<String, Error>)> {
+    hash_dir::<Sha256, _>(root, opts)
+}
+
+/// Recursively hashes every regular file under `root`, in parallel, returning one
+/// result per file, followed by an error entry for each directory or directory entry
+/// that could not be read.
+#[cfg(feature = "sha384")]
+pub fn hash_dir_sha384<P: AsRef<Path>>(
+    root: P,
+    opts: HashDirOptions,
+) -> Vec<(PathBuf, Result<String, Error>)> {
+    hash_dir::<Sha384, _>(root, opts)
+}
+
+/// Recursively hashes every regular file under `root`, in parallel, returning one
+/// result per file, followed by an error entry for each directory or directory entry
+/// that could not be read.
+#[cfg(feature = "sha512")]
+pub fn hash_dir_sha512<P: AsRef<Path>>(
+    root: P,
+    opts: HashDirOptions,
+) -> Vec<(PathBuf, Result<String, Error>)> {
+    hash_dir::<Sha512, _>(root, opts)
+}
+
+fn hash_dir<D, P>(root: P, opts: HashDirOptions) -> Vec<(PathBuf, Result<String, Error>)>
+where
+    D: Digest,
+    P: AsRef<Path>,
+{
+    let (files, errors) = collect_files(root.as_ref());
+    let total = files.len();
+    let done = AtomicUsize::new(0);
+
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(opts.jobs.max(1))
+        .build()
+        .expect("failed to build rayon thread pool");
+
+    let mut results: Vec<(PathBuf, Result<String, Error>)> = pool.install(|| {
+        files
+            .into_par_iter()
+            .map(|path| {
+                let result = hash_file::<D, _>(&path);
+                let completed = done.fetch_add(1, Ordering::Relaxed) + 1;
+                if let Some(progress) = &opts.progress {
+                    progress(completed, total);
+                }
+                (path, result)
+            })
+            .collect()
+    });
+    results.extend(errors);
+    results
+}
+
+/// Walks `root` depth-first, returning every regular file found alongside an error entry
+/// for each directory or directory entry that could not be read (permission denied,
+/// removed mid-walk, ...). Does not follow symlinks, so a symlink cycle under `root`
+/// cannot cause an infinite walk.
+fn collect_files(root: &Path) -> (Vec<PathBuf>, Vec<(PathBuf, Result<String, Error>)>) {
+    let mut files = Vec::new();
+    let mut errors = Vec::new();
+    let mut stack = vec![root.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(err) => {
+                errors.push((dir, Err(err)));
+                continue;
+            }
+        };
+        for entry in entries {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(err) => {
+                    errors.push((dir.clone(), Err(err)));
+                    continue;
+                }
+            };
+            let path = entry.path();
+            let file_type = match entry.file_type() {
+                Ok(file_type) => file_type,
+                Err(err) => {
+                    errors.push((path, Err(err)));
+                    continue;
+                }
+            };
+            if file_type.is_dir() {
+                stack.push(path);
+            } else if file_type.is_file() {
+                files.push(path);
+            }
+        }
+    }
+    (files, errors)
+}
+
+/// The outcome of checking one [`verify_manifest`] entry against the file on disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckResult {
+    /// The file exists and its digest matches the manifest.
+    Ok,
+    /// The file exists but its digest does not match the manifest.
+    Mismatch { expected: String, actual: String },
+    /// The file listed in the manifest does not exist.
+    Missing,
+    /// The file exists but could not be hashed (permission denied, removed mid-scan, ...).
+    Error(String),
+}
+
+/// Parses a `sha256sum`-style checksum manifest (`<hexdigest>␣␣<relative-path>`, with
+/// an optional `*` binary marker before the path) and checks each listed file, resolving
+/// relative paths against the manifest's directory.
+pub fn verify_manifest<P>(path: P, algo: Algorithm) -> Result<Vec<(PathBuf, CheckResult)>, Error>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let contents = fs::read_to_string(path)?;
+
+    let mut results = Vec::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let Some((expected, rel_path)) = parse_manifest_line(line) else {
+            continue;
+        };
+        let expected = expected.to_ascii_lowercase();
+        let file = dir.join(rel_path);
+
+        let result = if !file.is_file() {
+            CheckResult::Missing
+        } else {
+            match file.hash(algo) {
+                Ok(actual) if constant_time_eq(actual.as_bytes(), expected.as_bytes()) => {
+                    CheckResult::Ok
+                }
+                Ok(actual) => CheckResult::Mismatch { expected, actual },
+                Err(err) => CheckResult::Error(err.to_string()),
+            }
+        };
+        results.push((PathBuf::from(rel_path), result));
+    }
+    Ok(results)
+}
+
+/// Writes a `sha256sum`-style checksum manifest listing the digest of each file in
+/// `files`, relative to `manifest`'s directory.
+pub fn write_manifest<P, F, I>(manifest: P, algo: Algorithm, files: I) -> Result<(), Error>
+where
+    P: AsRef<Path>,
+    F: AsRef<Path>,
+    I: IntoIterator<Item = F>,
+{
+    let manifest = manifest.as_ref();
+    let dir = manifest.parent().unwrap_or_else(|| Path::new("."));
+
+    let mut contents = String::new();
+    for file in files {
+        let file = file.as_ref();
+        let digest = file.hash(algo)?;
+        let rel_path = relative_to(file, dir)?;
+        writeln!(contents, "{digest}  {}", rel_path.display())
+            .expect("writing to a String never fails");
+    }
+    fs::write(manifest, contents)
+}
+
+pub(crate) fn parse_manifest_line(line: &str) -> Option<(&str, &str)> {
+    let mut parts = line.splitn(2, char::is_whitespace);
+    let digest = parts.next()?;
+    let rest = parts.next()?.trim_start();
+    Some((digest, rest.strip_prefix('*').unwrap_or(rest)))
+}
+
+/// Computes `file`'s path relative to `dir`, for use as a manifest entry. Errors if
+/// `file` is not located under `dir`, since a manifest entry that doesn't resolve back
+/// to `file` when `dir.join(entry)` is re-applied during verification would silently
+/// report the file as missing instead of checking it.
+pub(crate) fn relative_to(file: &Path, dir: &Path) -> Result<PathBuf, Error> {
+    let canonical_file = file.canonicalize()?;
+    let canonical_dir = dir.canonicalize()?;
+    canonical_file
+        .strip_prefix(&canonical_dir)
+        .map(PathBuf::from)
+        .map_err(|_| {
+            Error::new(
+                InvalidInput,
+                format!("{} is not located under {}", file.display(), dir.display()),
+            )
+        })
+}